@@ -1,72 +1,323 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+use std::mem::size_of;
 
 use anyhow::Result;
 use windows::{
     Win32::{
-        Foundation::{COLORREF, HWND, LPARAM, LRESULT, POINT, RECT, WPARAM},
+        Foundation::{BOOL, COLORREF, HWND, LPARAM, LRESULT, POINT, RECT, WPARAM},
         Graphics::Gdi::{
-            BeginPaint, CreatePen, Ellipse, EndPaint, HDC, InvalidateRect, PAINTSTRUCT, PS_SOLID,
-            SelectObject, UpdateWindow,
+            BI_RGB, BITMAPINFO, BITMAPINFOHEADER, BeginPaint, BitBlt, CreateCompatibleDC,
+            CreateDIBSection, CreatePen, CreateSolidBrush, DIB_RGB_COLORS, DeleteDC, DeleteObject,
+            Ellipse, EndPaint, EnumDisplayMonitors, FillRect, HBITMAP, HDC, HMONITOR,
+            HGDIOBJ, InvalidateRect, PAINTSTRUCT, PS_SOLID, SRCCOPY, SelectObject, UpdateWindow,
         },
-        UI::WindowsAndMessaging::{
-            CW_USEDEFAULT, CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW,
-            GetWindowRect, MSG, MSLLHOOKSTRUCT, PostQuitMessage, RegisterClassW, SW_SHOW,
-            ShowWindow, TranslateMessage, WINDOW_EX_STYLE, WM_CREATE, WM_DESTROY, WM_PAINT,
-            WM_USER, WNDCLASSW, WS_CAPTION, WS_OVERLAPPED, WS_SYSMENU, WS_VISIBLE,
+        System::Registry::{HKEY_CURRENT_USER, RRF_RT_REG_DWORD, RegGetValueW},
+        UI::{
+            HiDpi::{
+                DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2, GetDpiForSystem, GetDpiForWindow,
+                SetProcessDpiAwarenessContext,
+            },
+            Input::{
+                GetRawInputData, HRAWINPUT, RAWINPUT, RAWINPUTDEVICE, RAWINPUTHEADER, RID_INPUT,
+                RIDEV_INPUTSINK, RegisterRawInputDevices,
+            },
+            WindowsAndMessaging::{
+                CW_USEDEFAULT, CreateWindowExW, DefWindowProcW, DispatchMessageW, GetClientRect,
+                GetCursorPos, GetMessageW, GetWindowRect, LWA_COLORKEY, MSG,
+                PostQuitMessage, RegisterClassW, SW_SHOW, SWP_NOACTIVATE, SWP_NOZORDER,
+                SetLayeredWindowAttributes, SetWindowPos, ShowWindow, TranslateMessage,
+                HTCAPTION, WINDOW_EX_STYLE, WM_CREATE, WM_DESTROY, WM_DISPLAYCHANGE,
+                WM_DPICHANGED, WM_ERASEBKGND, WM_INPUT, WM_NCHITTEST, WM_PAINT, WM_SETTINGCHANGE,
+                WM_SIZE, WNDCLASSW, WS_CAPTION,
+                WS_EX_LAYERED, WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_OVERLAPPED, WS_POPUP,
+                WS_SYSMENU, WS_VISIBLE,
+            },
         },
     },
     core::{Owned, PCWSTR, w},
 };
 
-#[link(name = "hook.dll", kind = "static")]
-unsafe extern "C" {
-    fn set_hook(hwnd: HWND) -> bool;
-    fn end_hook() -> bool;
+const CLASS_NAME: PCWSTR = w!("xeyes_window_class");
+const DEFAULT_DPI: f32 = 96.0;
+
+#[derive(Debug, Clone, Copy)]
+struct Theme {
+    outline: COLORREF,
+    iris: COLORREF,
+    background: COLORREF,
 }
 
-const CLASS_NAME: PCWSTR = w!("xeyes_window_class");
-const WM_HOOK_MOUSE_POS: u32 = WM_USER + 42;
+const LIGHT_THEME: Theme = Theme {
+    outline: COLORREF(0x00000000),
+    iris: COLORREF(0x00000000),
+    background: COLORREF(0x00FFFFFF),
+};
+
+const DARK_THEME: Theme = Theme {
+    outline: COLORREF(0x00FFFFFF),
+    iris: COLORREF(0x00FFFFFF),
+    background: COLORREF(0x00202020),
+};
+
+// painted as fully transparent when an overlay WindowMode is active
+const TRANSPARENT_KEY: COLORREF = COLORREF(0x00FF00FF);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WindowMode {
+    Normal,
+    /// Borderless and always-on-top with `WS_EX_TRANSPARENT`: every click
+    /// passes through to whatever is underneath, so the window can't be
+    /// dragged by its body. Click-through and dragging are mutually
+    /// exclusive, so draggable overlays use `OverlayDraggable` instead.
+    OverlayClickThrough,
+    /// Borderless and always-on-top like `OverlayClickThrough`, but without
+    /// `WS_EX_TRANSPARENT`: the body catches clicks and responds to
+    /// `WM_NCHITTEST` with `HTCAPTION` so it can be dragged, at the cost of
+    /// no longer being click-through.
+    OverlayDraggable,
+}
+
+impl WindowMode {
+    fn is_overlay(self) -> bool {
+        matches!(self, Self::OverlayClickThrough | Self::OverlayDraggable)
+    }
+}
+
+fn window_mode() -> WindowMode {
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|arg| arg == "--overlay-drag") {
+        WindowMode::OverlayDraggable
+    } else if args.iter().any(|arg| arg == "--overlay") {
+        WindowMode::OverlayClickThrough
+    } else {
+        WindowMode::Normal
+    }
+}
 
 thread_local! {
     static POS: Cell<Option<POINT>> = const { Cell::new(None) };
+    static BACK_BUFFER: RefCell<Option<BackBuffer>> = const { RefCell::new(None) };
+    static THEME: Cell<Theme> = const { Cell::new(LIGHT_THEME) };
+    static MODE: Cell<WindowMode> = const { Cell::new(WindowMode::Normal) };
+    static VIRTUAL_DESKTOP: Cell<RECT> = const {
+        Cell::new(RECT {
+            left: 0,
+            top: 0,
+            right: 0,
+            bottom: 0,
+        })
+    };
+}
+
+fn system_uses_light_theme() -> bool {
+    let mut value: u32 = 1;
+    let mut size = size_of::<u32>() as u32;
+    let result = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            w!(r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize"),
+            w!("AppsUseLightTheme"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut value as *mut u32 as *mut _),
+            Some(&mut size),
+        )
+    };
+    result.is_err() || value != 0
+}
+
+fn current_theme() -> Theme {
+    if system_uses_light_theme() {
+        LIGHT_THEME
+    } else {
+        DARK_THEME
+    }
 }
 
-fn draw_circle(hdc: HDC, top: i32, left: i32, bottom: i32, right: i32) {
-    let pen = unsafe { CreatePen(PS_SOLID, 10, COLORREF::default()) };
+struct BackBuffer {
+    dc: HDC,
+    bitmap: HBITMAP,
+    old_bitmap: HGDIOBJ,
+    width: i32,
+    height: i32,
+}
+
+impl BackBuffer {
+    fn new(width: i32, height: i32) -> Result<Self> {
+        let dc = unsafe { CreateCompatibleDC(None) };
+
+        let bmi = BITMAPINFO {
+            bmiHeader: BITMAPINFOHEADER {
+                biSize: size_of::<BITMAPINFOHEADER>() as u32,
+                biWidth: width,
+                biHeight: -height,
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: BI_RGB.0 as u32,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut bits = std::ptr::null_mut();
+        let bitmap =
+            unsafe { CreateDIBSection(Some(dc), &bmi, DIB_RGB_COLORS, &mut bits, None, 0)? };
+        let old_bitmap = unsafe { SelectObject(dc, bitmap.into()) };
+
+        Ok(Self {
+            dc,
+            bitmap,
+            old_bitmap,
+            width,
+            height,
+        })
+    }
+}
+
+impl Drop for BackBuffer {
+    fn drop(&mut self) {
+        unsafe {
+            // a bitmap still selected into a DC can't be deleted, so swap the
+            // original stock bitmap back in first
+            SelectObject(self.dc, self.old_bitmap);
+            _ = DeleteObject(self.bitmap.into());
+            _ = DeleteDC(self.dc);
+        }
+    }
+}
+
+fn dpi_scale(hwnd: HWND) -> f32 {
+    unsafe { GetDpiForWindow(hwnd) as f32 / DEFAULT_DPI }
+}
+
+unsafe extern "system" fn accumulate_monitor_rect(
+    _monitor: HMONITOR,
+    _hdc: HDC,
+    rect: *mut RECT,
+    bounds: LPARAM,
+) -> BOOL {
+    let rect = unsafe { *rect };
+    let bounds = unsafe { &mut *(bounds.0 as *mut RECT) };
+    bounds.left = bounds.left.min(rect.left);
+    bounds.top = bounds.top.min(rect.top);
+    bounds.right = bounds.right.max(rect.right);
+    bounds.bottom = bounds.bottom.max(rect.bottom);
+    true.into()
+}
+
+// GetWindowRect and GetCursorPos already report virtual-screen coordinates
+// (they can be negative on a monitor left of the primary one), so the eye
+// centers and the cursor position are already in the same coordinate space
+// without any extra conversion - there's no separate "compute eye centers in
+// virtual-screen space" step to add. The one thing that can go stale is the
+// cursor position itself if a monitor is unplugged, so clamp it to the
+// current virtual desktop before using it to aim the irises.
+fn virtual_desktop_rect() -> RECT {
+    let mut bounds = RECT {
+        left: i32::MAX,
+        top: i32::MAX,
+        right: i32::MIN,
+        bottom: i32::MIN,
+    };
+    unsafe {
+        _ = EnumDisplayMonitors(
+            None,
+            None,
+            Some(accumulate_monitor_rect),
+            LPARAM(&mut bounds as *mut RECT as isize),
+        );
+    }
+    bounds
+}
+
+// The monitor layout only changes on WM_DISPLAYCHANGE, not on every mouse
+// move, so cache it instead of calling EnumDisplayMonitors from WM_PAINT.
+fn refresh_virtual_desktop_rect() {
+    VIRTUAL_DESKTOP.set(virtual_desktop_rect());
+}
+
+fn resize_back_buffer(hwnd: HWND) -> Result<()> {
+    let mut rect = RECT::default();
+    _ = unsafe { GetClientRect(hwnd, &mut rect) };
+    let back_buffer = BackBuffer::new(rect.right - rect.left, rect.bottom - rect.top)?;
+    BACK_BUFFER.set(Some(back_buffer));
+    Ok(())
+}
+
+fn register_raw_input(hwnd: HWND) -> Result<()> {
+    let device = RAWINPUTDEVICE {
+        usUsagePage: 0x01,
+        usUsage: 0x02,
+        dwFlags: RIDEV_INPUTSINK,
+        hwndTarget: hwnd,
+    };
+    unsafe { RegisterRawInputDevices(&[device], size_of::<RAWINPUTDEVICE>() as u32)? };
+    Ok(())
+}
+
+fn draw_circle(
+    hdc: HDC,
+    top: i32,
+    left: i32,
+    bottom: i32,
+    right: i32,
+    scale: f32,
+    outline: COLORREF,
+    fill: COLORREF,
+) {
+    let pen = unsafe { CreatePen(PS_SOLID, (10.0 * scale) as i32, outline) };
     let pen = unsafe { Owned::new(pen) };
     let old_pen = unsafe { SelectObject(hdc, (*pen).into()) };
 
+    let brush = unsafe { CreateSolidBrush(fill) };
+    let brush = unsafe { Owned::new(brush) };
+    let old_brush = unsafe { SelectObject(hdc, (*brush).into()) };
+
     _ = unsafe { Ellipse(hdc, left, top, right, bottom) };
 
     unsafe { SelectObject(hdc, old_pen) };
+    unsafe { SelectObject(hdc, old_brush) };
 }
 
-fn draw_iris(hdc: HDC, mouse_pos: POINT, center_of_eye: POINT, offset_x: f32) {
+fn draw_iris(
+    hdc: HDC,
+    mouse_pos: POINT,
+    center_of_eye: POINT,
+    offset_x: f32,
+    scale: f32,
+    color: COLORREF,
+) {
     let dx_from_eye = mouse_pos.x - center_of_eye.x;
     let dy_from_eye = mouse_pos.y - center_of_eye.y;
 
     let distance_from_eye = (dx_from_eye.pow(2) as f32 + dy_from_eye.pow(2) as f32).sqrt();
 
     if distance_from_eye > 0.0 {
-        let dia = if distance_from_eye > 50.0 {
-            50.0
+        let clamp = 50.0 * scale;
+        let dia = if distance_from_eye > clamp {
+            clamp
         } else {
             distance_from_eye
         };
         let iris_pos = POINT {
             x: (dia * dx_from_eye as f32 / distance_from_eye / 1.76 + offset_x) as _,
 
-            y: (dia * dy_from_eye as f32 / distance_from_eye + 80.0) as _,
+            y: (dia * dy_from_eye as f32 / distance_from_eye + 80.0 * scale) as _,
         };
 
+        let iris_radius = 18.0 * scale;
+        let iris_half_width = 10.0 * scale;
         draw_circle(
             hdc,
-            iris_pos.y - 18,
-            iris_pos.x - 10,
-            iris_pos.y + 18,
-            iris_pos.x + 10,
+            (iris_pos.y as f32 - iris_radius) as _,
+            (iris_pos.x as f32 - iris_half_width) as _,
+            (iris_pos.y as f32 + iris_radius) as _,
+            (iris_pos.x as f32 + iris_half_width) as _,
+            scale,
+            color,
+            color,
         );
     }
 }
@@ -78,47 +329,202 @@ unsafe extern "system" fn wnd_proc(
     lparam: LPARAM,
 ) -> LRESULT {
     match msg {
-        WM_CREATE => unsafe {
-            set_hook(hwnd);
-        },
-        WM_DESTROY => unsafe {
-            end_hook();
-            PostQuitMessage(0)
-        },
-        WM_HOOK_MOUSE_POS => {
-            let ms = unsafe { &*(lparam.0 as *const MSLLHOOKSTRUCT) };
-            POS.set(Some(ms.pt));
-            _ = unsafe { InvalidateRect(Some(hwnd), None, true) };
+        WM_CREATE => {
+            THEME.set(current_theme());
+            refresh_virtual_desktop_rect();
+        }
+        WM_DESTROY => unsafe { PostQuitMessage(0) },
+        WM_DISPLAYCHANGE => {
+            refresh_virtual_desktop_rect();
+        }
+        WM_NCHITTEST if MODE.get() == WindowMode::OverlayDraggable => {
+            return LRESULT(HTCAPTION as isize);
+        }
+        WM_SETTINGCHANGE => {
+            let changed = if lparam.0 != 0 {
+                unsafe { PCWSTR::from_raw(lparam.0 as *const u16).to_string() }
+                    .unwrap_or_default()
+            } else {
+                String::new()
+            };
+            if changed == "ImmersiveColorSet" {
+                THEME.set(current_theme());
+                _ = unsafe { InvalidateRect(Some(hwnd), None, false) };
+            }
+        }
+        WM_DPICHANGED => {
+            let suggested_rect = unsafe { &*(lparam.0 as *const RECT) };
+            unsafe {
+                _ = SetWindowPos(
+                    hwnd,
+                    None,
+                    suggested_rect.left,
+                    suggested_rect.top,
+                    suggested_rect.right - suggested_rect.left,
+                    suggested_rect.bottom - suggested_rect.top,
+                    SWP_NOZORDER | SWP_NOACTIVATE,
+                );
+            }
+        }
+        WM_INPUT => {
+            let mut size = 0u32;
+            unsafe {
+                _ = GetRawInputData(
+                    HRAWINPUT(lparam.0 as _),
+                    RID_INPUT,
+                    None,
+                    &mut size,
+                    size_of::<RAWINPUTHEADER>() as u32,
+                );
+            }
+
+            let mut buf = vec![0u8; size as usize];
+            let copied = unsafe {
+                GetRawInputData(
+                    HRAWINPUT(lparam.0 as _),
+                    RID_INPUT,
+                    Some(buf.as_mut_ptr() as _),
+                    &mut size,
+                    size_of::<RAWINPUTHEADER>() as u32,
+                )
+            };
+            if copied == size {
+                let raw_input = unsafe { &*(buf.as_ptr() as *const RAWINPUT) };
+                if raw_input.header.dwType == 0 {
+                    // RIM_TYPEMOUSE == 0; raw mouse data is relative deltas, so
+                    // track the absolute screen position via GetCursorPos
+                    // instead of accumulating lLastX/lLastY
+                    let mut pt = POINT::default();
+                    if unsafe { GetCursorPos(&mut pt) }.is_ok() {
+                        POS.set(Some(pt));
+                        _ = unsafe { InvalidateRect(Some(hwnd), None, false) };
+                    }
+                }
+            }
+
+            // WM_INPUT must reach DefWindowProc so the system can release the
+            // raw input buffer behind this HRAWINPUT
+            return unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) };
+        }
+        WM_SIZE => {
+            _ = resize_back_buffer(hwnd);
         }
+        WM_ERASEBKGND => return LRESULT(1),
         WM_PAINT => {
+            let scale = dpi_scale(hwnd);
+
+            if BACK_BUFFER.with_borrow(Option::is_none) {
+                _ = resize_back_buffer(hwnd);
+            }
+
             let mut ps = PAINTSTRUCT::default();
-            let hdc = unsafe { BeginPaint(hwnd, &mut ps) };
-            // left eye
-            draw_circle(hdc, 5, 5, 155, 90);
-            // right eye
-            draw_circle(hdc, 5, 95, 155, 180);
-
-            let Some(mouse_pos) = POS.get() else {
-                _ = unsafe { EndPaint(hwnd, &ps) };
-                return LRESULT::default();
+            let window_hdc = unsafe { BeginPaint(hwnd, &mut ps) };
+
+            let theme = THEME.get();
+            let background_color = if MODE.get().is_overlay() {
+                TRANSPARENT_KEY
+            } else {
+                theme.background
             };
 
-            let mut rect = RECT::default();
-            _ = unsafe { GetWindowRect(hwnd, &mut rect) };
+            BACK_BUFFER.with_borrow(|back_buffer| {
+                let Some(back_buffer) = back_buffer else {
+                    return;
+                };
+                let hdc = back_buffer.dc;
 
-            let center_of_left_eye = POINT {
-                x: rect.left + 48,
-                y: rect.top + 110,
-            };
-            let center_of_right_eye = POINT {
-                x: center_of_left_eye.x + 90,
-                y: center_of_left_eye.y,
-            };
+                let background = unsafe { CreateSolidBrush(background_color) };
+                let background = unsafe { Owned::new(background) };
+                let client_rect = RECT {
+                    left: 0,
+                    top: 0,
+                    right: back_buffer.width,
+                    bottom: back_buffer.height,
+                };
+                unsafe { FillRect(hdc, &client_rect, *background) };
+
+                // left eye
+                draw_circle(
+                    hdc,
+                    (5.0 * scale) as _,
+                    (5.0 * scale) as _,
+                    (155.0 * scale) as _,
+                    (90.0 * scale) as _,
+                    scale,
+                    theme.outline,
+                    theme.background,
+                );
+                // right eye
+                draw_circle(
+                    hdc,
+                    (5.0 * scale) as _,
+                    (95.0 * scale) as _,
+                    (155.0 * scale) as _,
+                    (180.0 * scale) as _,
+                    scale,
+                    theme.outline,
+                    theme.background,
+                );
 
-            // left iris
-            draw_iris(hdc, mouse_pos, center_of_left_eye, 48.0);
-            // right iris
-            draw_iris(hdc, mouse_pos, center_of_right_eye, 138.0);
+                if let Some(mouse_pos) = POS.get() {
+                    let desktop = VIRTUAL_DESKTOP.get();
+                    let within_desktop =
+                        desktop.left <= desktop.right && desktop.top <= desktop.bottom;
+                    let mouse_pos = if within_desktop {
+                        POINT {
+                            x: mouse_pos.x.clamp(desktop.left, desktop.right),
+                            y: mouse_pos.y.clamp(desktop.top, desktop.bottom),
+                        }
+                    } else {
+                        mouse_pos
+                    };
+
+                    let mut rect = RECT::default();
+                    _ = unsafe { GetWindowRect(hwnd, &mut rect) };
+
+                    let center_of_left_eye = POINT {
+                        x: rect.left + (48.0 * scale) as i32,
+                        y: rect.top + (110.0 * scale) as i32,
+                    };
+                    let center_of_right_eye = POINT {
+                        x: center_of_left_eye.x + (90.0 * scale) as i32,
+                        y: center_of_left_eye.y,
+                    };
+
+                    // left iris
+                    draw_iris(
+                        hdc,
+                        mouse_pos,
+                        center_of_left_eye,
+                        48.0 * scale,
+                        scale,
+                        theme.iris,
+                    );
+                    // right iris
+                    draw_iris(
+                        hdc,
+                        mouse_pos,
+                        center_of_right_eye,
+                        138.0 * scale,
+                        scale,
+                        theme.iris,
+                    );
+                }
+
+                _ = unsafe {
+                    BitBlt(
+                        window_hdc,
+                        0,
+                        0,
+                        back_buffer.width,
+                        back_buffer.height,
+                        Some(hdc),
+                        0,
+                        0,
+                        SRCCOPY,
+                    )
+                };
+            });
 
             _ = unsafe { EndPaint(hwnd, &ps) };
         }
@@ -128,6 +534,11 @@ unsafe extern "system" fn wnd_proc(
 }
 
 fn main() -> Result<()> {
+    unsafe { SetProcessDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2)? };
+
+    let mode = window_mode();
+    MODE.set(mode);
+
     let wc = WNDCLASSW {
         lpfnWndProc: Some(wnd_proc),
         lpszClassName: CLASS_NAME,
@@ -136,16 +547,37 @@ fn main() -> Result<()> {
 
     unsafe { RegisterClassW(&wc) };
 
+    // CreateWindowExW runs before the window has a monitor to ask GetDpiForWindow
+    // about, so fall back to the system DPI (the primary monitor's DPI at process
+    // startup) to size the initial window; WM_DPICHANGED takes over from there.
+    let initial_scale = unsafe { GetDpiForSystem() } as f32 / DEFAULT_DPI;
+    let initial_size = (200.0 * initial_scale) as i32;
+
+    let (ex_style, style) = match mode {
+        WindowMode::Normal => (
+            WINDOW_EX_STYLE::default(),
+            WS_OVERLAPPED | WS_CAPTION | WS_VISIBLE | WS_SYSMENU,
+        ),
+        WindowMode::OverlayClickThrough => (
+            WS_EX_LAYERED | WS_EX_TRANSPARENT | WS_EX_TOPMOST,
+            WS_POPUP | WS_VISIBLE,
+        ),
+        WindowMode::OverlayDraggable => (
+            WS_EX_LAYERED | WS_EX_TOPMOST,
+            WS_POPUP | WS_VISIBLE,
+        ),
+    };
+
     let hwnd = unsafe {
         CreateWindowExW(
-            WINDOW_EX_STYLE::default(),
+            ex_style,
             CLASS_NAME,
             w!("xeyes"),
-            WS_OVERLAPPED | WS_CAPTION | WS_VISIBLE | WS_SYSMENU,
+            style,
             CW_USEDEFAULT,
             CW_USEDEFAULT,
-            200,
-            200,
+            initial_size,
+            initial_size,
             None,
             None,
             None,
@@ -153,6 +585,12 @@ fn main() -> Result<()> {
         )?
     };
 
+    if mode.is_overlay() {
+        unsafe { SetLayeredWindowAttributes(hwnd, TRANSPARENT_KEY, 0, LWA_COLORKEY)? };
+    }
+
+    register_raw_input(hwnd)?;
+
     unsafe { ShowWindow(hwnd, SW_SHOW).ok()? };
     unsafe { UpdateWindow(hwnd).ok()? };
 